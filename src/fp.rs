@@ -0,0 +1,94 @@
+//! Minimal `f64` transcendentals (`ln`/`exp`), reimplemented on top of bit
+//! manipulation and integer casts rather than a libm dependency, so the crate
+//! stays `no_std` without pulling in an external math library. `f64::abs` is
+//! core-compatible already (it's intrinsic-backed, not a libm call), so it's
+//! used directly at call sites instead of being reimplemented here.
+
+/// Natural log of `x`, for `x > 0`.
+///
+/// Splits `x = m * 2^e` (via the IEEE-754 bit layout, `m` in `[1, 2)`) and
+/// sums the `atanh`-based series `ln(m) = 2*atanh((m-1)/(m+1))`, which
+/// converges quickly since `m`'s range keeps the series argument `<= 1/3`.
+pub(crate) fn ln(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7FF) as i32 - 1023;
+    let m = f64::from_bits((bits & 0x000F_FFFF_FFFF_FFFF) | (1023u64 << 52));
+
+    let z = (m - 1.0) / (m + 1.0);
+    let z2 = z * z;
+    let mut term = z;
+    let mut sum = z;
+    for n in 1..9 {
+        term *= z2;
+        sum += term / (2 * n + 1) as f64;
+    }
+
+    2.0 * sum + exponent as f64 * core::f64::consts::LN_2
+}
+
+/// `e^x`.
+///
+/// Reduces `x = k*ln(2) + r` with `|r| <= ln(2)/2`, sums the Taylor series
+/// for `e^r`, and reassembles `e^x = e^r * 2^k`, with `2^k` built directly
+/// from the IEEE-754 exponent bits (flushing to `0.0`/`inf` the same way the
+/// format itself underflows/overflows).
+pub(crate) fn exp(x: f64) -> f64 {
+    let k = if x >= 0.0 {
+        (x / core::f64::consts::LN_2 + 0.5) as i64
+    } else {
+        (x / core::f64::consts::LN_2 - 0.5) as i64
+    };
+    let r = x - k as f64 * core::f64::consts::LN_2;
+
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for n in 1..18 {
+        term *= r / n as f64;
+        sum += term;
+    }
+
+    sum * pow2(k)
+}
+
+/// `2^k` for an arbitrary integer `k`, built from the IEEE-754 exponent bits.
+///
+/// Only covers normal `f64`s (`k` in `[-1022, 1023]`); anything smaller
+/// flushes to `0.0` rather than constructing a subnormal result, which is
+/// precise enough for `exp`'s use here (values that small are indistinguishable
+/// from the true result being rejected downstream anyway).
+fn pow2(k: i64) -> f64 {
+    if k >= 1024 {
+        f64::INFINITY
+    } else if k <= -1023 {
+        0.0
+    } else {
+        f64::from_bits(((k + 1023) as u64) << 52)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ln_matches_known_values() {
+        assert!((ln(1.0) - 0.0).abs() < 1e-9);
+        assert!((ln(core::f64::consts::E) - 1.0).abs() < 1e-9);
+        assert!((ln(0.5) - (-core::f64::consts::LN_2)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_exp_matches_known_values() {
+        assert!((exp(0.0) - 1.0).abs() < 1e-9);
+        assert!((exp(1.0) - core::f64::consts::E).abs() < 1e-9);
+        assert!((exp(-20.0) - 2.061_153_622_438_558e-9).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_exp_and_ln_round_trip() {
+        for &x in &[0.1, 1.0, 3.654, -5.0, -0.001, 10.0_f64] {
+            let x = x.abs().max(1e-300);
+            assert!((exp(ln(x)) - x).abs() < 1e-6);
+        }
+    }
+}
@@ -6,11 +6,21 @@ use core::{
     marker::PhantomData,
 };
 
+mod fp;
+mod normal;
+mod rngs;
+mod seq;
+mod weighted;
+mod ziggurat_tables;
+
+pub use rngs::{Pcg32, SplitMix64, Xorshift128Plus};
+pub use weighted::WeightedIndex;
+
 /// Requirements for compatible PRNG.
 pub trait PicoRandRNG
 where
     Self::Input: TryFrom<u128>,
-    Self::Output: TryFrom<u128>,
+    Self::Output: Into<u128>,
 {
     /// Input type for the PRNG.
     type Input;
@@ -21,8 +31,114 @@ where
     fn new(seed: Self::Input) -> Self;
     /// Generate a new number using the PRNG.
     fn rand(&mut self) -> Self::Output;
-    /// Constrain a randomly generated number to a fixed range.
-    fn rand_range(&mut self, min: usize, max: usize) -> Self::Output;
+
+    /// Combine as many [`rand`](PicoRandRNG::rand) draws as needed to produce
+    /// a uniform value with at least `want_bits` bits of entropy, returning
+    /// exactly its top `want_bits` bits.
+    ///
+    /// Backends whose native output is narrower than `want_bits` (e.g.
+    /// [`Pcg32`]'s `u32`) get their draws folded together a whole draw at a
+    /// time, rather than assuming every backend's raw output occupies a
+    /// fixed, wider layout.
+    fn rand_bits(&mut self, want_bits: u32) -> u128 {
+        let bits = (core::mem::size_of::<Self::Output>() * 8) as u32;
+        let mut draw_bits = bits;
+        while draw_bits < want_bits {
+            draw_bits += bits;
+        }
+
+        let mut raw = 0u128;
+        for _ in 0..(draw_bits / bits) {
+            raw = (raw << bits) | self.rand().into();
+        }
+
+        raw >> (draw_bits - want_bits)
+    }
+
+    // Adapted from Lemire's nearly-divisionless bounded random generation,
+    // https://lemire.me/blog/2019/06/06/nearly-divisionless-random-integer-generation-on-various-systems/
+    /// Constrain a randomly generated number to a fixed, fully-inclusive range
+    /// `[min, max]`. Shared by every [`PicoRandRNG`] implementor so that all
+    /// distributions built on top of it (range generation, shuffling, etc.)
+    /// work the same way across backends.
+    ///
+    /// Returns a `u128` rather than [`Output`](PicoRandRNG::Output): a
+    /// requested range can be wider than a single backend's native output
+    /// (e.g. a `u64`-wide range drawn through [`Pcg32`], whose `Output` is
+    /// only `u32`), so the result isn't guaranteed to fit in `Output` at all.
+    fn rand_range(&mut self, min: usize, max: usize) -> u128 {
+        // Width of the range, computed in `u128` so a full-width `usize` span
+        // (`min == 0, max == usize::MAX`) doesn't overflow. `range == 0` here
+        // only occurs for an invalid `min > max` (a full `usize` span lands on
+        // `2^64`, which fits comfortably in a `u128`); treat it the same as a
+        // full-width range and return the raw output unconstrained.
+        let range = (max as u128).wrapping_sub(min as u128).wrapping_add(1);
+        if range == 0 {
+            return self.rand().into();
+        }
+
+        // Lemire's method scales a raw draw by `range` and splits the
+        // product into a low half (rejected against a threshold) and a high
+        // half (the result). The draw must be at least as wide as `range`
+        // itself, or the threshold could never be reached — e.g. a 32-bit
+        // `Pcg32` draw asked for a range spanning more than `u32::MAX` would
+        // loop forever. `rand_bits` folds together as many native-width
+        // draws as needed to cover `range`'s bit width.
+        let bits = (core::mem::size_of::<Self::Output>() * 8) as u32;
+        let needed_bits = if range > 1 { 128 - (range - 1).leading_zeros() } else { 0 };
+        let draw_bits = core::cmp::max(bits, needed_bits);
+
+        // For `min <= max`, `draw_bits` is at most the platform's pointer
+        // width (`range` is derived from `usize` values), so well under 128
+        // and `1u128 << draw_bits` can't overflow.
+        let threshold = (1u128 << draw_bits) % range;
+
+        loop {
+            let raw = self.rand_bits(draw_bits);
+            let m = raw.wrapping_mul(range);
+            let l = m & (u128::MAX >> (128 - draw_bits));
+
+            if l >= threshold {
+                return (min as u128).wrapping_add(m >> draw_bits);
+            }
+        }
+    }
+
+    /// Seed a new PRNG instance from a buffer of entropy bytes, folding
+    /// buffers shorter or longer than the seed width together via XOR.
+    fn from_bytes(seed: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        let mut acc = 0u64;
+        for chunk in seed.chunks(8) {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            acc ^= u64::from_le_bytes(word);
+        }
+
+        Self::new(Self::Input::try_from(acc as u128).unwrap_or_else(|_| unreachable!()))
+    }
+
+    /// Re-seed an existing PRNG instance in place.
+    fn reseed(&mut self, seed: Self::Input)
+    where
+        Self: Sized,
+    {
+        *self = Self::new(seed);
+    }
+
+    /// Seed a new PRNG instance by drawing entropy from another, already
+    /// seeded, [`PicoRandRNG`].
+    fn from_rng<O: PicoRandRNG>(other: &mut O) -> Self
+    where
+        Self: Sized,
+    {
+        let mut seed = [0u8; 16];
+        seed[0..8].copy_from_slice(&(other.rand().into() as u64).to_le_bytes());
+        seed[8..16].copy_from_slice(&(other.rand().into() as u64).to_le_bytes());
+        Self::from_bytes(&seed)
+    }
 }
 
 /// Requirement for implicitly bounded RNG.
@@ -54,23 +170,6 @@ impl PicoRandRNG for WyRand {
             .wrapping_mul((self.seed ^ 0xE7037ED1A0B428DB) as u128);
         ((x >> 64) ^ x) as u64
     }
-
-    // Adapted from https://github.com/lemire/FastShuffleExperiments
-    /// Constrain a randomly generated number to a fixed range.
-    fn rand_range(&mut self, min: usize, max: usize) -> Self::Output {
-        let t = (-(max as i64)).checked_rem(max as i64).unwrap_or(0) as u64;
-        let (mut x, mut m, mut l);
-
-        while {
-            x = self.rand();
-            m = (x as u128).wrapping_mul(max as u128);
-            l = m as u64;
-
-            l < t
-        } {}
-
-        ((m >> 64) as u64).clamp(min as _, max as _)
-    }
 }
 
 /// An abstraction over a PRNG with a specific seed.
@@ -79,15 +178,17 @@ pub struct RNG<R: PicoRandRNG = WyRand, T = u64> {
     _marker: PhantomData<fn() -> T>,
 }
 
-impl<R: PicoRandRNG, T> RNG<R, T>
-where
-    <R as PicoRandRNG>::Output: TryInto<T>,
-{
+impl<R: PicoRandRNG, T> RNG<R, T> {
     /// Create a new [`RNG`] instance using a specific PRNG and a specific seed.
     pub fn new(seed: R::Input) -> Self {
         RNG::<R, T> { rng: R::new(seed), _marker: PhantomData }
     }
+}
 
+impl<R: PicoRandRNG, T> RNG<R, T>
+where
+    <R as PicoRandRNG>::Output: TryInto<T>,
+{
     /// Generate a number in the specified range.
     ///
     /// # Example
@@ -103,10 +204,7 @@ where
         <R as PicoRandRNG>::Output: Into<u128>,
         T: Default + TryFrom<u128>,
     {
-        u128::try_from(self.rng.rand_range(min, max))
-            .unwrap()
-            .try_into()
-            .unwrap_or_default() // Unreachable
+        T::try_from(self.rng.rand_range(min, max)).unwrap_or_default() // Unreachable
     }
 }
 
@@ -128,7 +226,7 @@ macro_rules! ImplPicoRandCommon {
             /// assert!(generated >= u32::MIN || generated <= u32::MAX);
             /// ```
             fn generate(&mut self) -> $type {
-                u128::try_from(self.rng.rand_range($type::MIN as usize, $type::MAX as usize)).unwrap() as _
+                self.rng.rand_range($type::MIN as usize, $type::MAX as usize) as _
             }
         }
     };
@@ -136,6 +234,46 @@ macro_rules! ImplPicoRandCommon {
 
 ImplPicoRandCommon!(for u8, u16, u32, u64);
 
+impl<R: PicoRandRNG> PicoRandGenerate<R, f32> for RNG<R, f32>
+where
+    <R as PicoRandRNG>::Output: Into<u128>,
+{
+    /// Generate a uniform `f32` in `[0, 1)`, stuffing 24 bits of PRNG entropy
+    /// (via [`rand_bits`](PicoRandRNG::rand_bits)) into the mantissa.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use picorand::{RNG, WyRand, PicoRandGenerate};
+    /// let mut rng = RNG::<WyRand, f32>::new(0xDEADBEEF);
+    /// let generated: f32 = rng.generate();
+    /// assert!(generated >= 0.0 && generated < 1.0);
+    /// ```
+    fn generate(&mut self) -> f32 {
+        self.rng.rand_bits(24) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+}
+
+impl<R: PicoRandRNG> PicoRandGenerate<R, f64> for RNG<R, f64>
+where
+    <R as PicoRandRNG>::Output: Into<u128>,
+{
+    /// Generate a uniform `f64` in `[0, 1)`, stuffing 53 bits of PRNG entropy
+    /// (via [`rand_bits`](PicoRandRNG::rand_bits)) into the mantissa.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use picorand::{RNG, WyRand, PicoRandGenerate};
+    /// let mut rng = RNG::<WyRand, f64>::new(0xDEADBEEF);
+    /// let generated: f64 = rng.generate();
+    /// assert!(generated >= 0.0 && generated < 1.0);
+    /// ```
+    fn generate(&mut self) -> f64 {
+        self.rng.rand_bits(53) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,4 +310,127 @@ mod tests {
     }
 
     ImplPicoRandTest!(for u8, u16, u32, u64);
+
+    #[test]
+    fn test_picorand_generate_f32() {
+        let mut rng = RNG::<WyRand, f32>::new(0xDEADBEEF);
+        for _ in 0..100 {
+            let generated: f32 = rng.generate();
+            assert!((0.0..1.0).contains(&generated));
+        }
+    }
+
+    #[test]
+    fn test_picorand_generate_f64() {
+        let mut rng = RNG::<WyRand, f64>::new(0xDEADBEEF);
+        for _ in 0..100 {
+            let generated: f64 = rng.generate();
+            assert!((0.0..1.0).contains(&generated));
+        }
+    }
+
+    #[test]
+    fn test_picorand_generate_f32_on_a_narrow_output_backend() {
+        // Regression test: `Pcg32`'s native `u32` output used to make every
+        // single `f32` draw come out as exactly `0.0`, since the bit-stuffing
+        // shift assumed a 64-bit-wide raw output.
+        let mut rng = RNG::<Pcg32, f32>::new(0xDEADBEEF);
+        let mut saw_nonzero = false;
+        for _ in 0..50 {
+            let generated: f32 = rng.generate();
+            assert!((0.0..1.0).contains(&generated));
+            saw_nonzero |= generated != 0.0;
+        }
+        assert!(saw_nonzero);
+    }
+
+    #[test]
+    fn test_picorand_generate_f64_on_a_narrow_output_backend() {
+        let mut rng = RNG::<Pcg32, f64>::new(0xDEADBEEF);
+        let mut saw_nonzero = false;
+        for _ in 0..50 {
+            let generated: f64 = rng.generate();
+            assert!((0.0..1.0).contains(&generated));
+            saw_nonzero |= generated != 0.0;
+        }
+        assert!(saw_nonzero);
+    }
+
+    #[test]
+    fn test_rand_range_can_return_upper_bound() {
+        // `rand_range` is documented as inclusive of both endpoints; make sure
+        // the upper bound is actually reachable rather than just clamped to.
+        let mut rng = WyRand::new(0xDEADBEEF);
+        let mut saw_max = false;
+        for _ in 0..10_000 {
+            if rng.rand_range(0, 1) == 1 {
+                saw_max = true;
+                break;
+            }
+        }
+        assert!(saw_max);
+    }
+
+    #[test]
+    fn test_rand_range_can_return_both_endpoints_of_a_narrow_range() {
+        let mut rng = WyRand::new(0xDEADBEEF);
+        let (mut saw_min, mut saw_max) = (false, false);
+        for _ in 0..10_000 {
+            match rng.rand_range(41, 42) {
+                41 => saw_min = true,
+                42 => saw_max = true,
+                other => panic!("{other} is outside of [41, 42]"),
+            }
+        }
+        assert!(saw_min && saw_max);
+    }
+
+    #[test]
+    fn test_rand_range_stays_within_bounds() {
+        let mut rng = WyRand::new(0xDEADBEEF);
+        for _ in 0..10_000 {
+            let generated = rng.rand_range(0xC0, 0xDE);
+            assert!((0xC0..=0xDE).contains(&generated));
+        }
+    }
+
+    #[test]
+    fn test_rand_range_handles_a_full_width_u64_span() {
+        // This is the case that used to overflow before `rand_range` was
+        // redesigned to do its arithmetic in `u128`.
+        let mut rng = WyRand::new(0xDEADBEEF);
+        for _ in 0..1_000 {
+            rng.rand_range(u64::MIN as usize, u64::MAX as usize);
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_is_deterministic() {
+        let mut a = WyRand::from_bytes(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        let mut b = WyRand::from_bytes(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(a.rand(), b.rand());
+    }
+
+    #[test]
+    fn test_from_bytes_folds_buffers_longer_than_the_seed() {
+        let mut rng = WyRand::from_bytes(&[0x42; 24]);
+        let _ = rng.rand();
+    }
+
+    #[test]
+    fn test_reseed_restarts_the_sequence() {
+        let mut rng = WyRand::new(0xDEADBEEF);
+        let first = rng.rand();
+        rng.rand();
+        rng.rand();
+        rng.reseed(0xDEADBEEF);
+        assert_eq!(rng.rand(), first);
+    }
+
+    #[test]
+    fn test_from_rng_derives_a_usable_instance() {
+        let mut source = WyRand::new(0xDEADBEEF);
+        let mut derived = WyRand::from_rng(&mut source);
+        let _ = derived.rand();
+    }
 }
@@ -0,0 +1,165 @@
+//! Ziggurat-based normal (Gaussian) distribution sampling, analogous to
+//! `rand_distr`'s `normal.rs` / `ziggurat_tables.rs`.
+
+use crate::fp::{exp, ln as log};
+use crate::ziggurat_tables::{ZIG_NORM_F, ZIG_NORM_X};
+use crate::{PicoRandRNG, RNG};
+
+impl<R: PicoRandRNG, T> RNG<R, T>
+where
+    <R as PicoRandRNG>::Output: Into<u128>,
+{
+    /// Draw a sample from a normal distribution with the given `mean` and
+    /// `std_dev`, using the Ziggurat algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use picorand::{WyRand, RNG};
+    /// let mut rng = RNG::<WyRand, u32>::new(0xDEADBEEF);
+    /// let sample = rng.generate_normal(0.0, 1.0);
+    /// assert!(sample.is_finite());
+    /// ```
+    pub fn generate_normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        mean + self.sample_standard_normal() * std_dev
+    }
+
+    fn sample_standard_normal(&mut self) -> f64 {
+        loop {
+            // The low byte of a single draw picks the Ziggurat layer; the
+            // rest of that same draw supplies the top of the mantissa for a
+            // uniform value in [-1, 1), topped up with extra draws (via
+            // `rand_bits`) only if the backend's native width doesn't leave
+            // enough bits after removing the index byte (e.g. `Pcg32`'s
+            // `u32`). This keeps wide (64-bit) backends at one draw per
+            // iteration, same as before narrower backends were supported.
+            let raw: u128 = self.rng.rand().into();
+            let i = (raw as usize) & 0xFF;
+            let native_bits = (core::mem::size_of::<<R as PicoRandRNG>::Output>() * 8) as u32;
+            let have = native_bits.saturating_sub(8);
+            let remaining = raw >> native_bits.min(8);
+            let mantissa = if have >= 56 {
+                remaining >> (have - 56)
+            } else {
+                (remaining << (56 - have)) | self.rng.rand_bits(56 - have)
+            };
+            let u = mantissa as f64 * (2.0 / (1u64 << 56) as f64) - 1.0;
+
+            if i == 0 {
+                // Base strip: its rectangle `[0, ZIG_NORM_X[0]] x [0, ZIG_NORM_F[0]]`
+                // sits entirely under the curve (ZIG_NORM_F[0] is the curve's
+                // minimum over that span), so it never needs a wedge test of
+                // its own — only a decision between "rectangle" and "tail".
+                // `p_rect` is the rectangle's share of the strip's total area
+                // (rectangle + tail), derived from the same area-per-layer
+                // invariant as every other layer (`ZIG_NORM_X[0] * (ZIG_NORM_F[1]
+                // - ZIG_NORM_F[0])`); rescaling `u` by it turns "accept" back
+                // into a uniform draw over the full rectangle width.
+                let p_rect = ZIG_NORM_F[0] / (ZIG_NORM_F[1] - ZIG_NORM_F[0]);
+                if u.abs() < p_rect {
+                    return (u / p_rect) * ZIG_NORM_X[0];
+                }
+
+                let tail_x = loop {
+                    let tx = -log(self.uniform_open()) / ZIG_NORM_X[0];
+                    let ty = -log(self.uniform_open());
+                    if 2.0 * ty > tx * tx {
+                        break ZIG_NORM_X[0] + tx;
+                    }
+                };
+                return if u < 0.0 { -tail_x } else { tail_x };
+            }
+
+            // Layer `i`'s rectangle has width `ZIG_NORM_X[i - 1]` (its widest
+            // point, shared with the layer below) and meets the next layer in
+            // at `ZIG_NORM_X[i]`; anything narrower than that is guaranteed
+            // under the curve for the whole layer and accepts immediately.
+            let width = ZIG_NORM_X[i - 1];
+            let x = u * width;
+
+            if u.abs() * width < ZIG_NORM_X[i] {
+                return x;
+            }
+
+            // Wedge rejection: accept with probability proportional to how far
+            // below the true density curve the rectangle strip sits.
+            let f = self.uniform_open();
+            if ZIG_NORM_F[i] + f * (ZIG_NORM_F[i - 1] - ZIG_NORM_F[i]) < exp(-0.5 * x * x) {
+                return x;
+            }
+        }
+    }
+
+    /// Uniform `f64` in `(0, 1)`, suitable as input to `log`.
+    fn uniform_open(&mut self) -> f64 {
+        loop {
+            let v = self.rng.rand_bits(53) as f64 * (1.0 / (1u64 << 53) as f64);
+            if v > 0.0 {
+                return v;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Pcg32, WyRand};
+
+    #[test]
+    fn test_generate_normal_is_finite_and_roughly_centered() {
+        let mut rng = RNG::<WyRand, u32>::new(0xDEADBEEF);
+        let mut sum = 0.0;
+        let n = 10_000;
+        for _ in 0..n {
+            let sample = rng.generate_normal(0.0, 1.0);
+            assert!(sample.is_finite());
+            sum += sample;
+        }
+        let mean = sum / n as f64;
+        assert!(mean.abs() < 0.1);
+    }
+
+    #[test]
+    fn test_generate_normal_is_roughly_centered_on_a_narrow_output_backend() {
+        // Regression test: `Pcg32`'s native `u32` output used to leave the
+        // mantissa bits in `sample_standard_normal` zero-padded instead of
+        // randomized, badly skewing the distribution.
+        let mut rng = RNG::<Pcg32, u32>::new(0xDEADBEEF);
+        let mut sum = 0.0;
+        let n = 10_000;
+        for _ in 0..n {
+            let sample = rng.generate_normal(0.0, 1.0);
+            assert!(sample.is_finite());
+            sum += sample;
+        }
+        let mean = sum / n as f64;
+        assert!(mean.abs() < 0.1);
+    }
+
+    #[test]
+    fn test_generate_normal_covers_the_band_just_past_the_outermost_layer() {
+        // Regression test: an off-by-one in the layer width/boundary indices
+        // used to leave `|x|` in `(ZIG_NORM_X[1], ZIG_NORM_X[0])` completely
+        // unreachable, no matter how many samples were drawn.
+        let mut rng = RNG::<WyRand, u32>::new(0xDEADBEEF);
+        let mut saw_band = false;
+        for _ in 0..200_000 {
+            let sample = rng.generate_normal(0.0, 1.0);
+            if sample.abs() > 3.5 && sample.abs() < 3.65 {
+                saw_band = true;
+                break;
+            }
+        }
+        assert!(saw_band);
+    }
+
+    #[test]
+    fn test_generate_normal_respects_mean_and_std_dev() {
+        let mut rng = RNG::<WyRand, u32>::new(0xDEADBEEF);
+        for _ in 0..1_000 {
+            let sample = rng.generate_normal(100.0, 0.0);
+            assert_eq!(sample, 100.0);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Additional [`PicoRandRNG`] backends beyond the default [`WyRand`](crate::WyRand).
+
+use crate::PicoRandRNG;
+
+/// A SplitMix64 PRNG instance. Note: This PRNG is NOT cryptographically secure.
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl PicoRandRNG for SplitMix64 {
+    /// Input type for the PRNG.
+    type Input = u64;
+    /// Output type for the PRNG.
+    type Output = u64;
+
+    /// Create a new [`SplitMix64`] instance using a specific seed.
+    fn new(seed: Self::Input) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    /// Generate a new number using the [`SplitMix64`] PRNG.
+    fn rand(&mut self) -> Self::Output {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// A PCG32 (`pcg_xsh_rr_64_32`) PRNG instance. Note: This PRNG is NOT cryptographically secure.
+pub struct Pcg32 {
+    state: u64,
+    inc: u64,
+}
+
+impl Pcg32 {
+    /// Advance the internal LCG state by one step, discarding any output.
+    fn step(&mut self) {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(self.inc);
+    }
+}
+
+impl PicoRandRNG for Pcg32 {
+    /// Input type for the PRNG.
+    type Input = u64;
+    /// Output type for the PRNG.
+    type Output = u32;
+
+    /// Create a new [`Pcg32`] instance using a specific seed, following the
+    /// canonical `pcg32_srandom_r` warm-up.
+    fn new(seed: Self::Input) -> Self {
+        let mut rng = Pcg32 { state: 0, inc: 0x0A02_BDBF_7BB3_C0A7 | 1 };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    /// Generate a new number using the [`Pcg32`] PRNG.
+    fn rand(&mut self) -> Self::Output {
+        let prev = self.state;
+        self.step();
+
+        let xorshifted = (((prev >> 18) ^ prev) >> 27) as u32;
+        let rot = (prev >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+}
+
+/// A Xorshift128+ PRNG instance. Note: This PRNG is NOT cryptographically secure.
+pub struct Xorshift128Plus {
+    s0: u64,
+    s1: u64,
+}
+
+impl PicoRandRNG for Xorshift128Plus {
+    /// Input type for the PRNG.
+    type Input = u64;
+    /// Output type for the PRNG.
+    type Output = u64;
+
+    /// Create a new [`Xorshift128Plus`] instance using a specific seed.
+    ///
+    /// The single 64-bit seed is expanded into the two 64-bit state words via
+    /// [`SplitMix64`], as recommended for seeding xorshift generators.
+    fn new(seed: Self::Input) -> Self {
+        let mut seeder = SplitMix64::new(seed);
+        Xorshift128Plus { s0: seeder.rand(), s1: seeder.rand() }
+    }
+
+    /// Generate a new number using the [`Xorshift128Plus`] PRNG.
+    fn rand(&mut self) -> Self::Output {
+        let s0 = self.s1;
+        let mut s1 = self.s0;
+        let result = s0.wrapping_add(s1);
+
+        s1 ^= s1 << 23;
+        s1 ^= s1 >> 17;
+        s1 ^= s0 ^ (s0 >> 26);
+
+        self.s0 = s0;
+        self.s1 = s1;
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PicoRandGenerate, RNG};
+
+    macro_rules! ImplBackendTest {
+        ($backend:ident, $generate_test:ident, $range_test:ident) => {
+            #[test]
+            fn $generate_test() {
+                let mut rng = RNG::<$backend, u32>::new(0xDEADBEEF);
+                for _ in 0..100 {
+                    let _ = rng.generate();
+                }
+            }
+
+            #[test]
+            fn $range_test() {
+                let mut rng = RNG::<$backend, u32>::new(0xDEADBEEF);
+                for _ in 0..100 {
+                    let generated = rng.generate_range(0xC0, 0xDE);
+                    assert!((0xC0..=0xDE).contains(&generated));
+                }
+            }
+        };
+    }
+
+    ImplBackendTest!(SplitMix64, test_splitmix64_generate, test_splitmix64_generate_range);
+    ImplBackendTest!(Pcg32, test_pcg32_generate, test_pcg32_generate_range);
+    ImplBackendTest!(
+        Xorshift128Plus,
+        test_xorshift128plus_generate,
+        test_xorshift128plus_generate_range
+    );
+
+    #[test]
+    fn test_pcg32_first_output_is_not_always_zero_for_small_seeds() {
+        // Regression test: seeding `state` directly to `seed` (skipping the
+        // canonical PCG warm-up) made `rand()`'s first output deterministically
+        // `0` for every seed below `2^18`.
+        let mut saw_nonzero = false;
+        for seed in 0..20u64 {
+            if Pcg32::new(seed).rand() != 0 {
+                saw_nonzero = true;
+                break;
+            }
+        }
+        assert!(saw_nonzero);
+    }
+
+    #[test]
+    fn test_pcg32_generate_u64_does_not_hang() {
+        // Regression test: `Pcg32`'s native `u32` output used to make
+        // `rand_range` loop forever whenever the requested span exceeded
+        // `u32::MAX`, which the default `RNG<Pcg32, u64>` does on every call.
+        let mut rng = RNG::<Pcg32, u64>::new(0xDEADBEEF);
+        for _ in 0..100 {
+            let _: u64 = rng.generate();
+        }
+    }
+
+    #[test]
+    fn test_pcg32_generate_range_spanning_u32_max_does_not_hang() {
+        let mut rng = RNG::<Pcg32, u64>::new(0xDEADBEEF);
+        for _ in 0..100 {
+            let _: u64 = rng.generate_range(0, u64::MAX as usize);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Slice sampling helpers, analogous to `rand`'s `seq` module.
+
+use crate::{PicoRandRNG, RNG};
+
+impl<R: PicoRandRNG, T> RNG<R, T>
+where
+    <R as PicoRandRNG>::Output: Into<u128>,
+{
+    /// Shuffle a slice in place using the Fisher–Yates algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use picorand::{WyRand, RNG};
+    /// let mut rng = RNG::<WyRand, u8>::new(0xDEADBEEF);
+    /// let mut values = [1, 2, 3, 4, 5];
+    /// rng.shuffle(&mut values);
+    /// ```
+    pub fn shuffle<E>(&mut self, slice: &mut [E]) {
+        let len = slice.len();
+        if len < 2 {
+            return;
+        }
+
+        for i in (1..len).rev() {
+            // `rand_range` is inclusive of both endpoints, so `j` can equal `i`.
+            let j = self.index(0, i);
+            slice.swap(i, j);
+        }
+    }
+
+    /// Pick a uniformly random element from a slice, or `None` if it's empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use picorand::{WyRand, RNG};
+    /// let mut rng = RNG::<WyRand, u8>::new(0xDEADBEEF);
+    /// let values = [1, 2, 3, 4, 5];
+    /// let picked = rng.choose(&values);
+    /// assert!(picked.is_some());
+    /// ```
+    pub fn choose<'a, E>(&mut self, slice: &'a [E]) -> Option<&'a E> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        let idx = self.index(0, slice.len() - 1);
+        slice.get(idx)
+    }
+
+    /// Draw a uniformly random `usize` in `[min, max]` from the underlying PRNG.
+    fn index(&mut self, min: usize, max: usize) -> usize {
+        self.rng.rand_range(min, max) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WyRand;
+
+    #[test]
+    fn test_shuffle_is_a_permutation() {
+        let mut rng = RNG::<WyRand, u8>::new(0xDEADBEEF);
+        let original = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut values = original;
+        rng.shuffle(&mut values);
+
+        for v in original {
+            assert!(values.contains(&v));
+        }
+    }
+
+    #[test]
+    fn test_shuffle_empty_and_single_are_noops() {
+        let mut rng = RNG::<WyRand, u8>::new(0xDEADBEEF);
+        let mut empty: [u8; 0] = [];
+        rng.shuffle(&mut empty);
+
+        let mut single = [42];
+        rng.shuffle(&mut single);
+        assert_eq!(single, [42]);
+    }
+
+    #[test]
+    fn test_choose_returns_element_in_bounds() {
+        let mut rng = RNG::<WyRand, u8>::new(0xDEADBEEF);
+        let values = [10, 20, 30, 40, 50];
+        for _ in 0..100 {
+            let picked = rng.choose(&values).unwrap();
+            assert!(values.contains(picked));
+        }
+    }
+
+    #[test]
+    fn test_choose_empty_slice_is_none() {
+        let mut rng = RNG::<WyRand, u8>::new(0xDEADBEEF);
+        let values: [u8; 0] = [];
+        assert_eq!(rng.choose(&values), None);
+    }
+}
@@ -0,0 +1,168 @@
+//! Weighted discrete index sampling via Vose's alias method, analogous to
+//! `rand`'s `weighted::alias_method`.
+
+use crate::{PicoRandRNG, RNG};
+
+/// A prebuilt alias table for drawing indices `0..N` proportional to a fixed
+/// weight table in `O(1)` per sample.
+///
+/// `N` is a const generic so the two working arrays live inline in the struct
+/// (stack or `static` storage, as the caller prefers) rather than behind an
+/// allocator, keeping this usable in a `no_std`/alloc-free context.
+pub struct WeightedIndex<const N: usize> {
+    prob: [u32; N],
+    alias: [usize; N],
+}
+
+impl<const N: usize> WeightedIndex<N> {
+    /// Build an alias table from a fixed weight table, using Vose's algorithm.
+    ///
+    /// A weight of `0` is valid and simply makes that index unreachable. If
+    /// every weight is `0`, samples fall back to a uniform distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N == 0` — an empty table has no valid index for
+    /// [`sample_weighted`](RNG::sample_weighted) to ever return.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use picorand::WeightedIndex;
+    /// let table = WeightedIndex::new([1u32, 2, 3, 4]);
+    /// ```
+    pub fn new(weights: [u32; N]) -> Self {
+        assert!(N > 0, "WeightedIndex must have at least one entry");
+
+        let total: u64 = weights.iter().map(|&w| w as u64).sum();
+
+        let mut prob = [u32::MAX; N];
+        let mut alias = [0usize; N];
+
+        if total == 0 {
+            return WeightedIndex { prob, alias };
+        }
+
+        // `num[i] / total` tracks index `i`'s current scaled weight, kept as
+        // an exact fraction over the shared denominator `total` to avoid any
+        // floating-point rounding.
+        let mut num = [0u64; N];
+        for (i, &w) in weights.iter().enumerate() {
+            num[i] = w as u64 * N as u64;
+        }
+
+        let mut small = [0usize; N];
+        let mut small_len = 0;
+        let mut large = [0usize; N];
+        let mut large_len = 0;
+
+        for (i, &scaled) in num.iter().enumerate() {
+            if scaled < total {
+                small[small_len] = i;
+                small_len += 1;
+            } else {
+                large[large_len] = i;
+                large_len += 1;
+            }
+        }
+
+        while small_len > 0 && large_len > 0 {
+            small_len -= 1;
+            let s = small[small_len];
+            large_len -= 1;
+            let l = large[large_len];
+
+            prob[s] = ((num[s] as u128 * u32::MAX as u128) / total as u128) as u32;
+            alias[s] = l;
+
+            num[l] = num[l] + num[s] - total;
+            if num[l] < total {
+                small[small_len] = l;
+                small_len += 1;
+            } else {
+                large[large_len] = l;
+                large_len += 1;
+            }
+        }
+
+        // The integer bookkeeping above is exact, so leftover entries sit at
+        // exactly their full scaled weight and are drawn unconditionally.
+        while large_len > 0 {
+            large_len -= 1;
+            prob[large[large_len]] = u32::MAX;
+        }
+        while small_len > 0 {
+            small_len -= 1;
+            prob[small[small_len]] = u32::MAX;
+        }
+
+        WeightedIndex { prob, alias }
+    }
+}
+
+impl<R: PicoRandRNG, T> RNG<R, T>
+where
+    <R as PicoRandRNG>::Output: Into<u128>,
+{
+    /// Draw an index `0..N` from a prebuilt [`WeightedIndex`], proportional to
+    /// the weights it was built from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use picorand::{WyRand, RNG, WeightedIndex};
+    /// let table = WeightedIndex::new([1u32, 2, 3, 4]);
+    /// let mut rng = RNG::<WyRand, u32>::new(0xDEADBEEF);
+    /// let picked = rng.sample_weighted(&table);
+    /// assert!(picked < 4);
+    /// ```
+    pub fn sample_weighted<const N: usize>(&mut self, weighted: &WeightedIndex<N>) -> usize {
+        let column = self.rng.rand_range(0, N - 1) as usize;
+        // `prob` is scaled so `u32::MAX` means "always": a coin in
+        // `[0, u32::MAX - 1]` lands under `u32::MAX` every time and under `0`
+        // never, so zero-weight columns are never returned.
+        let coin = self.rng.rand_range(0, u32::MAX as usize - 1) as u32;
+
+        if coin < weighted.prob[column] {
+            column
+        } else {
+            weighted.alias[column]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WyRand;
+
+    #[test]
+    #[should_panic(expected = "at least one entry")]
+    fn test_weighted_index_rejects_an_empty_table() {
+        let _ = WeightedIndex::new([0u32; 0]);
+    }
+
+    #[test]
+    fn test_weighted_never_samples_a_zero_weight_index() {
+        let table = WeightedIndex::new([0u32, 5, 0, 5]);
+        let mut rng = RNG::<WyRand, u32>::new(0xDEADBEEF);
+
+        for _ in 0..1000 {
+            let picked = rng.sample_weighted(&table);
+            assert!(picked == 1 || picked == 3);
+        }
+    }
+
+    #[test]
+    fn test_weighted_favors_higher_weights() {
+        let table = WeightedIndex::new([1u32, 100]);
+        let mut rng = RNG::<WyRand, u32>::new(0xDEADBEEF);
+
+        let mut counts = [0u32; 2];
+        for _ in 0..10_000 {
+            counts[rng.sample_weighted(&table)] += 1;
+        }
+
+        assert!(counts[1] > counts[0] * 10);
+    }
+}
@@ -0,0 +1,144 @@
+//! Precomputed 256-layer Ziggurat tables for standard-normal sampling.
+//!
+//! Generated by solving for the self-consistent layer boundary `R` (the point
+//! where the base layer meets the Gaussian tail) and constructing the remaining
+//! layers by the usual Marsaglia & Tsang recurrence. Index `0` is the base layer
+//! that covers the infinite tail; index `255` is the layer nearest the peak. The
+//! trailing entry at index `256` (`x = 0`, `f = 1`) closes the table at the
+//! peak; the sampler only reads indices `0..=255`.
+
+pub(crate) const ZIG_NORM_X: [f64; 257] = [
+    3.6540926381911514, 3.449214166810557, 3.320177780172679, 3.2245058142679346,
+    3.147818095232911, 3.0834532033714472, 3.027763290175417, 2.978527328283769,
+    2.934289562622163, 2.8940424746482325, 2.8570589425722317, 2.8227964539187522,
+    2.7908391231770064, 2.760860886827279, 2.732601208066741, 2.7058485034571405,
+    2.680428516208955, 2.6561959603653182, 2.633028388003483, 2.610821603961404,
+    2.589486180890249, 2.5689447715140354, 2.549130008280671, 2.5299828424138497,
+    2.51145121619657, 2.4934889911392917, 2.4760550748884103, 2.4591127041120684,
+    2.442628850983469, 2.4265737284765723, 2.410920375311212, 2.395644305593092,
+    2.380723211376971, 2.3661367088117244, 2.3518661203985447, 2.337894287348397,
+    2.324205407164137, 2.3107848924714505, 2.2976192478367112, 2.2846959618807507,
+    2.2720034124567965, 2.2595307830325613, 2.247267988718936, 2.2352056106351914,
+    2.223334837504038, 2.211647413537964, 2.200135591817727, 2.1887920924801265,
+    2.177610065129435, 2.1665830549686196, 2.1557049722153887, 2.1449700644264436,
+    2.134372891402871, 2.1239083023918184, 2.1135714153357066, 2.1033575979511707,
+    2.093262450446548, 2.08328178970969, 2.0734116348177216, 2.063648193737594,
+    2.0539878511012444, 2.044427156952209, 2.0349628163719333, 2.025591679904001,
+    2.016310734703242, 2.0071170963443827, 1.9980080012316843, 1.9889807995569897,
+    1.9800329487589068, 1.9711620074405376, 1.9623656297073382, 1.953641559890394,
+    1.9449876276237004, 1.936401743246982, 1.9278818935082227, 1.919426137542429,
+    1.9110326031052753, 1.902699483042163, 1.8944250319749503, 1.8862075631901316,
+    1.8780454457136426, 1.869937101558714, 1.8618810031343318, 1.8538756708028838,
+    1.8459196705765044, 1.8380116119424694, 1.8301501458087615, 1.8223339625616195,
+    1.8145617902275246, 1.8068323927326477, 1.799144568253314, 1.7914971476515251,
+    1.7838889929900097, 1.7763189961216894, 1.7687860773488042, 1.761289184147288,
+    1.7538272899522926, 1.7463993930010413, 1.7390045152294606, 1.7316417012192729,
+    1.7243100171924612, 1.7170085500502172, 1.7097364064536713, 1.7024927119438829,
+    1.695276610098721, 1.68808726172442, 1.6809238440797274, 1.6737855501306906,
+    1.6666715878342409, 1.6595811794488466, 1.6525135608706036, 1.6454679809932218,
+    1.638443701090458, 1.6314399942196158, 1.6244561446448154, 1.6174914472787922,
+    1.6105452071420592, 1.6036167388383116, 1.596705366045017, 1.5898104210181738,
+    1.5829312441102739, 1.576067183300539, 1.5692175937365445, 1.5623818372863754,
+    1.5555592821004927, 1.5487493021825138, 1.5419512769681434, 1.5351645909115044,
+    1.5283886330781498, 1.5216227967440477, 1.5148664789998487, 1.508119080359763,
+    1.5013800043743804, 1.4946486572467776, 1.4879244474512696, 1.4812067853541566,
+    1.474495082835832, 1.467788752913612, 1.4610872093646452, 1.4543898663482653,
+    1.447696138027133, 1.4410054381865176, 1.434317179851051, 1.4276307748982784,
+    1.420945633668318, 1.414261164568917, 1.4075767736751859, 1.4008918643232549,
+    1.3942058366970864, 1.3875180874076407, 1.380828009063564, 1.3741349898325363,
+    1.3674384129923753, 1.3607376564709526, 1.354032092373935, 1.3473210864993133,
+    1.3406039978376225, 1.3338801780567047, 1.3271489709697968, 1.320409711985656,
+    1.3136617275393578, 1.3069043345023188, 1.3001368395700046, 1.2933585386256825,
+    1.2865687160784722, 1.279766644173825, 1.2729515822744397, 1.266122776109476,
+    1.259279456989774, 1.252420840986625, 1.2455461280714495, 1.2386545012135397,
+    1.2317451254328087, 1.2248171468042397, 1.217869691410473, 1.210901864238677,
+    1.2039127480175307, 1.1969014019897977, 1.1898668606155918, 1.1828081322010042,
+    1.1757241974463033, 1.1686140079074028, 1.1614764843637206, 1.1543105150849315,
+    1.147114953988409, 1.1398886186783874, 1.1326302883570125, 1.125338701596493,
+    1.1180125539605024, 1.1106504954617908, 1.1032511278416395, 1.0958130016553027,
+    1.0883346131459166, 1.0808144008874807, 1.0732507421754078, 1.0656419491407716,
+    1.0579862645616862, 1.0502818573422268, 1.042526817625851, 1.034719151506373,
+    1.0268567752950855, 1.0189375092975435, 1.0109590710477065, 1.0029190679404714,
+    0.9948149891959562, 0.9866441970800762, 0.9784039172957277, 0.9700912284470877,
+    0.9617030504657785, 0.9532361318716374, 0.9446870357220959, 0.9360521240822071,
+    0.9273275408214994, 0.9185091925132947, 0.9095927271759188, 0.9005735105521431,
+    0.8914465995717054, 0.8822067125799707, 0.8728481958413223, 0.8633649857356889,
+    0.8537505659568795, 0.8439979188871611, 0.8340994701574375, 0.8240470251981966,
+    0.813831696332293, 0.8034438186423897, 0.7928728524445691, 0.7821072696899052,
+    0.7711344209633391, 0.7599403789072017, 0.7485097528004827, 0.736825467584204,
+    0.7248684987104878, 0.7126175516256356, 0.7000486712109573, 0.6871347617091774,
+    0.6738449909750122, 0.6601440434159236, 0.6459911723500391, 0.6313389825071315,
+    0.61613184346678, 0.6003037890116167, 0.5837756854457862, 0.5664513357355995,
+    0.5482119924262354, 0.5289084163836852, 0.5083490106883779, 0.48628140180955326,
+    0.46236249756516284, 0.43610694055856114, 0.4067916287092328, 0.3732608202980115,
+    0.3334702937761366, 0.2831743687165294, 0.210405617509207, 0.00504002376829804,
+    0.0,
+];
+
+pub(crate) const ZIG_NORM_F: [f64; 257] = [
+    0.0012605634136882067, 0.002609649953008629, 0.004038870335740604, 0.005523636368768252,
+    0.007052455814464531, 0.008618520638565741, 0.010217275951674891, 0.011845437310130335,
+    0.013500512670628715, 0.015180540182026815, 0.016883931626622918, 0.018609372820067565,
+    0.020355757042978814, 0.022122138756161095, 0.023907700359476293, 0.025711727663958524,
+    0.02753359137336986, 0.029372732824747116, 0.03122865281904435, 0.03310090274011395,
+    0.03498907739905415, 0.03689280920036414, 0.03881176333525906, 0.040745633783451106,
+    0.04269413995865905, 0.04465702387207992, 0.04663404771663322, 0.04862499179603166,
+    0.05062965273872462, 0.052647841948939136, 0.05467938425641662, 0.05672411673372923,
+    0.05878188765577481, 0.06085255558056993, 0.06293598853406535, 0.06503206328460351,
+    0.06714066469497981, 0.0692616851419757, 0.0713950239947934, 0.07354058714510867,
+    0.07569828658252328, 0.07786804001008653, 0.08004977049529696, 0.08224340615261957,
+    0.08444887985408037, 0.0866661289649465, 0.08889509510188039, 0.09113572391128058,
+    0.0933879648658007, 0.0956517710772771, 0.09792709912450256, 0.10021390889446258,
+    0.10251216343580588, 0.10482182882345677, 0.10714287403339451, 0.10947527082672952,
+    0.11181899364229612, 0.11417401949706263, 0.11654032789372966, 0.1189179007349503,
+    0.12130672224366158, 0.12370677888906505, 0.12611805931783934, 0.12854055429020572,
+    0.1309742566205032, 0.13341916112196095, 0.13587526455538398, 0.1383425655814933,
+    0.1408210647166843, 0.14331076429198858, 0.14581166841504162, 0.1483237829348769,
+    0.15084711540938164, 0.15338167507526312, 0.15592747282038774, 0.15848452115836598,
+    0.16105283420526725, 0.1636324276583581, 0.16622331877676613, 0.16882552636398024,
+    0.17143907075210474, 0.17406397378779262, 0.17670025881978876, 0.1793479506880203,
+    0.1820070757141762, 0.18467766169372424, 0.18735973788931676, 0.19005333502554247,
+    0.19275848528498446, 0.1954752223055493, 0.19820358117903503, 0.20094359845090967,
+    0.20369531212127517, 0.20645876164699414, 0.20923398794496018, 0.21202103339649525,
+    0.21481994185285996, 0.21763075864186524, 0.22045353057557637, 0.22328830595910268,
+    0.22613513460046794, 0.22899406782156012, 0.231865158470159, 0.2347484609330449,
+    0.2376440311501913, 0.2405519266300484, 0.24347220646592477, 0.24640493135347755,
+    0.24935016360932336, 0.25230796719078324, 0.2552784077167783, 0.25826155248989374,
+    0.26125747051963133, 0.26426623254687254, 0.26728791106957644, 0.27032258036973866,
+    0.27337031654164057, 0.2764311975214184, 0.27950530311798677, 0.28259271504535083,
+    0.2856935169563462, 0.28880779447784677, 0.2919356352474832, 0.2950771289519192,
+    0.29823236736673314, 0.30140144439795813, 0.3045844561253349, 0.3077815008473358,
+    0.310992679128022, 0.31421809384579913, 0.3174578502441404, 0.32071205598435076,
+    0.32398082120044863, 0.32726425855624836, 0.3305624833047282, 0.33387561334977706,
+    0.33720376931041485, 0.34054707458758976, 0.3439056554336604, 0.347279641024676,
+    0.35066916353557687, 0.3540743582184414, 0.357495363483916, 0.3609323209859696,
+    0.364385375710126, 0.36785467606533173, 0.3713403739796319, 0.37484262499983173,
+    0.3783615883953362, 0.3818974272663684, 0.3854503086567824, 0.38902040367169793,
+    0.3926078876001978, 0.3962129400433465, 0.3998357450478016, 0.40347649124530915,
+    0.4071353719983909, 0.41081258555255384, 0.41450833519537067, 0.41822282942280564,
+    0.4219562821131832, 0.4257089127092252, 0.429480946408609, 0.4332726143635328,
+    0.4370841538898051, 0.4409158086860133, 0.44476782906336576, 0.44864047218684217,
+    0.45253400232833557, 0.456448691132517, 0.46038481789620933, 0.4643426698621148,
+    0.4683225425278047, 0.47232473997094976, 0.4763495751918445, 0.480397370474363,
+    0.4844684577665714, 0.48856317908232383, 0.4926818869252741, 0.49682494473685407,
+    0.5009927273699005, 0.5051856215897532, 0.5094040266048026, 0.5136483546286404,
+    0.5179190314761529, 0.5222164971961085, 0.5265412067430194, 0.5308936306913181,
+    0.5352742559951665, 0.5396835867975358, 0.5441221452925409, 0.5485904726454055,
+    0.5530891299748649, 0.5576186994033, 0.5621797851804378, 0.5667730148870591,
+    0.5713990407258396, 0.5760585409072109, 0.5807522211389996, 0.5854808162295699,
+    0.5902450918153043, 0.595045846224503, 0.5998839124912086, 0.6047601605340742,
+    0.6096754995172454, 0.6146308804123382, 0.6196272987830159, 0.6246657978164611,
+    0.6297474716292477, 0.6348734688788416, 0.6400449967162666, 0.6452633251204934,
+    0.6505297916609621, 0.6558458067415089, 0.6612128593870297, 0.666632523643712,
+    0.6721064656749306, 0.677636451648271, 0.6832243565251189, 0.688872173883396,
+    0.6945820269270806, 0.7003561808640544, 0.7061970568677695, 0.7121072478797397,
+    0.7180895365609321, 0.7241469157633277, 0.7302826119716119, 0.7365001122636379,
+    0.7428031954629132, 0.7491959683149604, 0.7556829077228945, 0.7622689103409894,
+    0.768959351169231, 0.7757601532462742, 0.7826778711447008, 0.7897197917915696,
+    0.7968940572578537, 0.8042098157151223, 0.8116774089484184, 0.8193086079533212,
+    0.8271169127280985, 0.8351179392030097, 0.8433299266697724, 0.8517744153912392,
+    0.8604771703864442, 0.8694694712841246, 0.878789964282363, 0.8884874105013258,
+    0.8986249299977926, 0.9092868829407187, 0.9205907323710892, 0.9327091901851196,
+    0.9459162746781496, 0.9606992621994396, 0.978107925327946, 0.9999872991608638,
+    1.0,
+];